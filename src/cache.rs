@@ -0,0 +1,127 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+#[cfg(test)]
+use std::collections::HashMap;
+#[cfg(test)]
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+
+/// Identifies which cached resource a [`CacheStore`] call is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheKind {
+    Mappings,
+    Prices,
+}
+
+/// Storage backend for the raw JSON bodies returned by the prices API.
+///
+/// `Client` is generic over this trait so the on-disk cache used in
+/// production can be swapped for an in-memory one in tests, or for an
+/// alternative backend entirely.
+pub trait CacheStore {
+    /// Load the raw cached body for `kind`, if one has been stored.
+    fn load(&self, kind: CacheKind) -> Result<Option<String>>;
+
+    /// Persist `body` as the cached contents for `kind`.
+    fn store(&self, kind: CacheKind, body: &str) -> Result<()>;
+
+    /// Age of the cached entry for `kind`, or `None` if nothing is cached.
+    fn age(&self, kind: CacheKind) -> Result<Option<Duration>>;
+}
+
+/// Default [`CacheStore`] backed by flat JSON files on disk.
+pub struct FsCacheStore {
+    cache_dir: PathBuf,
+}
+
+impl FsCacheStore {
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self { cache_dir }
+    }
+
+    fn path(&self, kind: CacheKind) -> PathBuf {
+        self.cache_dir.join(match kind {
+            CacheKind::Mappings => "mappings.json",
+            CacheKind::Prices => "prices.json",
+        })
+    }
+}
+
+impl CacheStore for FsCacheStore {
+    fn load(&self, kind: CacheKind) -> Result<Option<String>> {
+        let path = self.path(kind);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        fs::read_to_string(path)
+            .map(Some)
+            .context("Unable to read cache file")
+    }
+
+    fn store(&self, kind: CacheKind, body: &str) -> Result<()> {
+        let path = self.path(kind);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Unable to create directories")?;
+        }
+
+        fs::write(path, body).context("Unable to save cache file")
+    }
+
+    fn age(&self, kind: CacheKind) -> Result<Option<Duration>> {
+        let path = self.path(kind);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let metadata = path.metadata().context("Unable to read cache metadata")?;
+        let mtime = metadata
+            .modified()
+            .context("Unable to access mtime for cache file")?;
+
+        let duration = SystemTime::now()
+            .duration_since(mtime)
+            .context("Cache file mtime should not be in the future")?;
+
+        Ok(Some(duration))
+    }
+}
+
+/// In-memory [`CacheStore`], useful for tests that shouldn't touch disk or
+/// race with other tests over shared cache files.
+#[cfg(test)]
+#[derive(Default)]
+pub struct MemoryCacheStore {
+    entries: Mutex<HashMap<CacheKind, (String, SystemTime)>>,
+}
+
+#[cfg(test)]
+impl MemoryCacheStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+impl CacheStore for MemoryCacheStore {
+    fn load(&self, kind: CacheKind) -> Result<Option<String>> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries.get(&kind).map(|(body, _)| body.clone()))
+    }
+
+    fn store(&self, kind: CacheKind, body: &str) -> Result<()> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(kind, (body.to_owned(), SystemTime::now()));
+        Ok(())
+    }
+
+    fn age(&self, kind: CacheKind) -> Result<Option<Duration>> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries
+            .get(&kind)
+            .map(|(_, stored_at)| SystemTime::now().duration_since(*stored_at).unwrap_or_default()))
+    }
+}
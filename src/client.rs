@@ -1,12 +1,9 @@
 use std::collections::HashMap;
-use std::fs::{self, File};
-use std::io::BufReader;
-use std::path::PathBuf;
-use std::time::SystemTime;
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
 
+use crate::cache::{CacheKind, CacheStore, FsCacheStore};
 use crate::config::ClientConfig;
 
 /// Struct containing id and name of objects to look up
@@ -28,64 +25,55 @@ pub struct PriceResults {
 }
 
 /// OSRS Prices API Client
-pub struct Client {
+pub struct Client<C: CacheStore = FsCacheStore> {
     client: reqwest::Client,
     config: ClientConfig,
+    cache: C,
 }
 
-impl Client {
+impl Client<FsCacheStore> {
+    /// Try to build Client from ClientConfig, caching to flat JSON files on disk
+    pub fn try_from_config(config: ClientConfig) -> Result<Self> {
+        let cache = FsCacheStore::new(config.cache_dir.clone());
+        Self::try_new(config, cache)
+    }
+}
+
+impl<C: CacheStore> Client<C> {
     const APP_USER_AGENT: &'static str =
         concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
     const MAPPING_URL: &'static str = "https://prices.runescape.wiki/api/v1/osrs/mapping";
     const PRICES_LATEST_URL: &'static str = "https://prices.runescape.wiki/api/v1/osrs/latest";
 
-    /// Try to build Client from ClientConfig
-    pub fn try_from_config(config: ClientConfig) -> Result<Self> {
+    /// Try to build Client from ClientConfig and a given cache backend
+    pub fn try_new(config: ClientConfig, cache: C) -> Result<Self> {
         let client = reqwest::Client::builder()
             .user_agent(Self::APP_USER_AGENT)
             .build()
             .context("Could not build reqwest::Client")?;
 
-        Ok(Self { client, config })
-    }
-
-    fn prices_cache(&self) -> PathBuf {
-        self.config.cache_dir.join("prices.json")
-    }
-
-    fn mappings_cache(&self) -> PathBuf {
-        self.config.cache_dir.join("mappings.json")
+        Ok(Self {
+            client,
+            config,
+            cache,
+        })
     }
 
     /// Checks if the mappings should be refreshed
-    fn should_refresh_mappings(&self) -> bool {
-        !self.mappings_cache().exists()
+    fn should_refresh_mappings(&self) -> Result<bool> {
+        Ok(self.cache.age(CacheKind::Mappings)?.is_none())
     }
 
-    fn should_refresh_prices(&self) -> bool {
-        let cache = self.prices_cache();
-        if !cache.exists() {
-            return true;
+    fn should_refresh_prices(&self) -> Result<bool> {
+        match self.cache.age(CacheKind::Prices)? {
+            None => Ok(true),
+            Some(age) => Ok(age > self.config.price_cache_ttl),
         }
-
-        // Check modified time, if > TTL ago, refresh
-        let metadata = cache.metadata().expect("Prices cache should exist");
-        let mtime = metadata
-            .modified()
-            .expect("Unable to access mtime for prices cache");
-
-        let duration = SystemTime::now()
-            .duration_since(mtime)
-            .expect("Prices cache mtime should not be in the future");
-
-        duration > self.config.price_cache_ttl
     }
 
     pub async fn get_mappings(&self, force_refresh: bool) -> Result<Vec<ItemMapping>> {
-        let cache = self.mappings_cache();
-
-        if force_refresh || self.should_refresh_mappings() {
+        if force_refresh || self.should_refresh_mappings()? {
             // Refresh mapping file if needed
             let body = self
                 .client
@@ -97,25 +85,21 @@ impl Client {
                 .await
                 .context("Failed to receive response")?;
 
-            if let Some(parent) = cache.parent() {
-                fs::create_dir_all(parent).context("Unable to create directories")?;
-            }
-
-            fs::write(cache, &body).context("Unable to save mapping data")?;
+            self.cache.store(CacheKind::Mappings, &body)?;
 
             serde_json::from_str(&body).context("Unable to parse response JSON")
         } else {
-            let reader =
-                BufReader::new(File::open(cache).context("Unable to open mappings cache file")?);
+            let body = self
+                .cache
+                .load(CacheKind::Mappings)?
+                .context("Unable to open mappings cache file")?;
 
-            serde_json::from_reader(reader).context("Unable to parse mappings cache file")
+            serde_json::from_str(&body).context("Unable to parse mappings cache file")
         }
     }
 
     pub async fn get_prices(&self, force_refresh: bool) -> Result<PriceResults> {
-        let cache = self.prices_cache();
-
-        if force_refresh || self.should_refresh_prices() {
+        if force_refresh || self.should_refresh_prices()? {
             // Refresh mapping file if needed
             let body = self
                 .client
@@ -127,18 +111,16 @@ impl Client {
                 .await
                 .context("Failed to receive response")?;
 
-            if let Some(parent) = cache.parent() {
-                fs::create_dir_all(parent).context("Unable to create directories")?;
-            }
-
-            fs::write(cache, &body).context("Unable to save prices data")?;
+            self.cache.store(CacheKind::Prices, &body)?;
 
             serde_json::from_str(&body).context("Unable to parse response JSON")
         } else {
-            let reader =
-                BufReader::new(File::open(cache).context("Unable to open prices cache file")?);
+            let body = self
+                .cache
+                .load(CacheKind::Prices)?
+                .context("Unable to open prices cache file")?;
 
-            serde_json::from_reader(reader).context("Unable to parse prices cache file")
+            serde_json::from_str(&body).context("Unable to parse prices cache file")
         }
     }
 }
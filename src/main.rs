@@ -1,3 +1,4 @@
+mod cache;
 mod client;
 mod config;
 
@@ -68,29 +69,44 @@ mod tests {
     use std::path::PathBuf;
     use std::time::Duration;
 
+    use crate::cache::{CacheKind, CacheStore, MemoryCacheStore};
     use crate::config::ClientConfig;
 
     use super::*;
 
+    const TEST_MAPPINGS: &str = r#"[
+        { "id": 1, "name": "Zulrah's scales" },
+        { "id": 2, "name": "Twisted bow" },
+        { "id": 3, "name": "Twisted buckler" },
+        { "id": 4, "name": "Twisted ancestral hat" },
+        { "id": 5, "name": "Twisted horns" },
+        { "id": 6, "name": "Abyssal whip" },
+        { "id": 7, "name": "Dragon scimitar" }
+    ]"#;
+
     #[tokio::test]
     async fn test_name_matching() {
-        let cache_dir: PathBuf = [env!("CARGO_MANIFEST_DIR"), "test_data"].iter().collect();
+        let cache = MemoryCacheStore::new();
+        cache
+            .store(CacheKind::Mappings, TEST_MAPPINGS)
+            .expect("Test mappings should be stored in the in-memory cache");
+
         let config = ClientConfig {
-            cache_dir,
+            cache_dir: PathBuf::new(),
             price_cache_ttl: Duration::from_secs(u64::MAX),
         };
 
-        let client = Client::try_from_config(config).expect("Client should be created");
+        let client = Client::try_new(config, cache).expect("Client should be created");
 
         let mappings = client
             .get_mappings(false)
             .await
-            .expect("Client mappings should be loaded from test data");
+            .expect("Client mappings should be loaded from the in-memory cache");
 
         let single_name_ids = get_matching_items("Zulrah's scales", &mappings).collect::<Vec<_>>();
         assert_eq!(single_name_ids.len(), 1);
 
         let multi_name_ids = get_matching_items("twisted", &mappings).collect::<Vec<_>>();
-        assert_eq!(multi_name_ids.len(), 23);
+        assert_eq!(multi_name_ids.len(), 4);
     }
 }